@@ -0,0 +1,30 @@
+/// A handle to the ONNX Runtime allocator used to create and own [`crate::value::Value`]s.
+///
+/// Allocators are owned by the [`crate::session::Session`] that created them and are borrowed for the lifetime of
+/// any tensor built from them.
+#[derive(Debug)]
+pub struct Allocator {
+	pub(crate) ptr: *mut ort_sys::OrtAllocator
+}
+
+impl Allocator {
+	pub(crate) fn new(ptr: *mut ort_sys::OrtAllocator) -> Self {
+		Allocator { ptr }
+	}
+
+	/// Returns the raw allocator pointer, for passing to ONNX Runtime C API calls that take an `OrtAllocator*`.
+	pub(crate) fn ptr(&self) -> *mut ort_sys::OrtAllocator {
+		self.ptr
+	}
+}
+
+unsafe impl Send for Allocator {}
+unsafe impl Sync for Allocator {}
+
+pub(crate) mod ort_sys {
+	/// Opaque handle to the underlying `OrtAllocator` C struct.
+	#[repr(C)]
+	pub struct OrtAllocator {
+		_private: [u8; 0]
+	}
+}
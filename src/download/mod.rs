@@ -0,0 +1,149 @@
+//! Helpers for fetching pre-trained models from the [ONNX Model Zoo](https://github.com/onnx/models) without
+//! having to manually source and place the model file on disk.
+//!
+//! See [`vision`] for the currently supported models. Each one implements [`ModelUrl`], which
+//! [`crate::session::SessionBuilder::with_model_downloaded`] uses to download, checksum, and cache the model
+//! before building a [`crate::session::Session`] from it.
+
+pub mod vision;
+
+use std::{
+	fs,
+	io::Read,
+	path::{Path, PathBuf}
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{OrtError, OrtResult};
+
+/// A model hosted in a well-known model zoo, identified by a download URL and, where pinned, an expected
+/// checksum.
+///
+/// Implementors also advertise the input/output dimensions ONNX Runtime will report for the model, so callers
+/// can validate them against `session.inputs[0].dimensions()` without having loaded the model yet.
+pub trait ModelUrl {
+	/// The URL the model file can be downloaded from.
+	fn model_url(&self) -> &'static str;
+	/// The expected SHA-256 checksum of the downloaded model file, as a lowercase hex string, if one has been
+	/// pinned against the upstream release. `download_model` skips verification when this is `None` rather
+	/// than rejecting a legitimate download against a placeholder value.
+	fn sha256(&self) -> Option<&'static str> {
+		None
+	}
+	/// The dimensions ONNX Runtime will report for this model's (sole) input.
+	fn input_dimensions(&self) -> &'static [Option<usize>];
+	/// The dimensions ONNX Runtime will report for this model's (sole) output.
+	fn output_dimensions(&self) -> &'static [Option<usize>];
+	/// The URL of the label file associated with this model (e.g. ImageNet class names), if any.
+	fn labels_url(&self) -> Option<&'static str> {
+		None
+	}
+}
+
+/// Returns the default directory models are cached in: `$TMPDIR/ort-model-zoo` (or the platform equivalent).
+pub fn default_cache_dir() -> PathBuf {
+	std::env::temp_dir().join("ort-model-zoo")
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+	let mut hasher = Sha256::new();
+	hasher.update(bytes);
+	hasher.finalize().iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn cached_file(cache_dir: &Path, url: &str) -> PathBuf {
+	let filename = url.rsplit('/').next().unwrap_or("model");
+	cache_dir.join(filename)
+}
+
+fn fetch(url: &str) -> OrtResult<Vec<u8>> {
+	let response = ureq::get(url).call().map_err(|e| OrtError::DownloadError(url.to_string(), e.to_string()))?;
+	let mut bytes = Vec::new();
+	response
+		.into_reader()
+		.read_to_end(&mut bytes)
+		.map_err(|e| OrtError::DownloadError(url.to_string(), e.to_string()))?;
+	Ok(bytes)
+}
+
+/// Downloads (if not already cached) the model file for `model` into `cache_dir` and returns the path to the
+/// cached file.
+///
+/// The file's SHA-256 is checked against [`ModelUrl::sha256`] when that returns a checksum; when it returns
+/// `None`, the file is used unverified.
+pub fn download_model<M: ModelUrl>(model: &M, cache_dir: impl AsRef<Path>) -> OrtResult<PathBuf> {
+	let cache_dir = cache_dir.as_ref();
+	fs::create_dir_all(cache_dir)?;
+
+	let path = cached_file(cache_dir, model.model_url());
+	let bytes = if path.exists() {
+		fs::read(&path)?
+	} else {
+		let bytes = fetch(model.model_url())?;
+		fs::write(&path, &bytes)?;
+		bytes
+	};
+
+	if let Some(expected) = model.sha256() {
+		let got = sha256_hex(&bytes);
+		if got != expected {
+			return Err(OrtError::ChecksumMismatch {
+				filename: path,
+				expected: expected.to_string(),
+				got
+			});
+		}
+	}
+
+	Ok(path)
+}
+
+/// Downloads (if not already cached) the label file associated with `model`, returning one label per line.
+///
+/// Returns an empty list if `model` has no associated label file.
+pub fn download_labels<M: ModelUrl>(model: &M, cache_dir: impl AsRef<Path>) -> OrtResult<Vec<String>> {
+	let Some(labels_url) = model.labels_url() else {
+		return Ok(Vec::new());
+	};
+
+	let cache_dir = cache_dir.as_ref();
+	fs::create_dir_all(cache_dir)?;
+
+	let path = cached_file(cache_dir, labels_url);
+	let bytes = if path.exists() {
+		fs::read(&path)?
+	} else {
+		let bytes = fetch(labels_url)?;
+		fs::write(&path, &bytes)?;
+		bytes
+	};
+
+	let text = std::str::from_utf8(&bytes)?;
+	Ok(parse_labels(text))
+}
+
+fn parse_labels(text: &str) -> Vec<String> {
+	text.lines().map(|l| l.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_labels_splits_one_label_per_line() {
+		assert_eq!(parse_labels("cat\ndog\nfish\n"), vec!["cat", "dog", "fish"]);
+	}
+
+	#[test]
+	fn parse_labels_of_empty_text_is_empty() {
+		assert!(parse_labels("").is_empty());
+	}
+
+	#[test]
+	fn sha256_hex_matches_a_known_digest() {
+		// SHA-256 of the empty byte string, a standard test vector.
+		assert_eq!(sha256_hex(b""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+	}
+}
@@ -0,0 +1,65 @@
+//! Pre-trained vision models from the ONNX Model Zoo.
+
+use super::ModelUrl;
+
+/// General-purpose image classification models trained on ImageNet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageClassification {
+	/// SqueezeNet 1.0, a small, fast model good for constrained environments.
+	SqueezeNet,
+	/// MobileNet v2, a model optimized for mobile and embedded vision applications.
+	MobileNet,
+	/// ResNet-50 v2, a deeper model trading some speed for higher top-1 accuracy.
+	ResNet50
+}
+
+impl ModelUrl for ImageClassification {
+	fn model_url(&self) -> &'static str {
+		match self {
+			ImageClassification::SqueezeNet => "https://github.com/onnx/models/raw/main/validated/vision/classification/squeezenet/model/squeezenet1.0-12.onnx",
+			ImageClassification::MobileNet => "https://github.com/onnx/models/raw/main/validated/vision/classification/mobilenet/model/mobilenetv2-12.onnx",
+			ImageClassification::ResNet50 => "https://github.com/onnx/models/raw/main/validated/vision/classification/resnet/model/resnet50-v2-7.onnx"
+		}
+	}
+
+	// No checksum is pinned for these yet: the exact bytes served by the model zoo's release assets need to be
+	// hashed against a real download before we can verify them here, rather than shipping a value that only
+	// looks plausible. `download_model` downloads and caches these models unverified until that's done.
+
+	fn input_dimensions(&self) -> &'static [Option<usize>] {
+		&[Some(1), Some(3), Some(224), Some(224)]
+	}
+
+	fn output_dimensions(&self) -> &'static [Option<usize>] {
+		&[Some(1), Some(1000)]
+	}
+
+	fn labels_url(&self) -> Option<&'static str> {
+		Some("https://raw.githubusercontent.com/onnx/models/main/validated/vision/classification/synset.txt")
+	}
+}
+
+/// Image classification models specialized to a particular domain, rather than general ImageNet categories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainBasedImageClassification {
+	/// Classifies a handwritten digit (0-9), trained on MNIST.
+	Mnist
+}
+
+impl ModelUrl for DomainBasedImageClassification {
+	fn model_url(&self) -> &'static str {
+		match self {
+			DomainBasedImageClassification::Mnist => "https://github.com/onnx/models/raw/main/validated/vision/classification/mnist/model/mnist-12.onnx"
+		}
+	}
+
+	// See the checksum note in `ImageClassification`'s `ModelUrl` impl above: no checksum is pinned for this model.
+
+	fn input_dimensions(&self) -> &'static [Option<usize>] {
+		&[Some(1), Some(1), Some(28), Some(28)]
+	}
+
+	fn output_dimensions(&self) -> &'static [Option<usize>] {
+		&[Some(1), Some(10)]
+	}
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use crate::{error::OrtResult, execution_providers::ExecutionProviderDispatch};
+
+/// The severity of a log message emitted by ONNX Runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoggingLevel {
+	/// Verbose logging, including per-node timing.
+	Verbose,
+	/// Informational messages.
+	Info,
+	/// Only warnings and above.
+	Warning,
+	/// Only errors.
+	Error,
+	/// Only fatal errors.
+	Fatal
+}
+
+/// An [`Environment`] is the top-level state shared by all [`crate::session::Session`]s created from it: the
+/// ONNX Runtime logging configuration and the default set of execution providers.
+///
+/// Most applications only need a single `Environment`, built once at startup and shared (via [`Environment::into_arc`])
+/// across every session.
+#[derive(Debug)]
+pub struct Environment {
+	name: String,
+	log_level: LoggingLevel,
+	execution_providers: Vec<ExecutionProviderDispatch>
+}
+
+impl Environment {
+	/// Creates an [`EnvironmentBuilder`] to configure a new `Environment`.
+	pub fn builder() -> EnvironmentBuilder {
+		EnvironmentBuilder::default()
+	}
+
+	/// Wraps this `Environment` in an [`Arc`] so it can be shared across multiple sessions.
+	pub fn into_arc(self) -> Arc<Environment> {
+		Arc::new(self)
+	}
+
+	pub(crate) fn execution_providers(&self) -> &[ExecutionProviderDispatch] {
+		&self.execution_providers
+	}
+
+	pub(crate) fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub(crate) fn log_level(&self) -> LoggingLevel {
+		self.log_level
+	}
+}
+
+/// Builder for configuring an [`Environment`] before it is constructed.
+#[derive(Debug, Default)]
+pub struct EnvironmentBuilder {
+	name: Option<String>,
+	log_level: Option<LoggingLevel>,
+	execution_providers: Vec<ExecutionProviderDispatch>
+}
+
+impl EnvironmentBuilder {
+	/// Sets the name ONNX Runtime will use to identify this environment in log output.
+	pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	/// Sets the minimum severity of log messages ONNX Runtime will emit.
+	pub fn with_log_level(mut self, log_level: LoggingLevel) -> Self {
+		self.log_level = Some(log_level);
+		self
+	}
+
+	/// Sets the default list of execution providers that sessions created from this environment will attempt to
+	/// register, in priority order, unless overridden by [`crate::session::SessionBuilder::with_execution_providers`].
+	pub fn with_execution_providers(mut self, execution_providers: impl IntoIterator<Item = ExecutionProviderDispatch>) -> Self {
+		self.execution_providers = execution_providers.into_iter().collect();
+		self
+	}
+
+	/// Finalizes the environment configuration.
+	pub fn build(self) -> OrtResult<Environment> {
+		Ok(Environment {
+			name: self.name.unwrap_or_else(|| "default".to_string()),
+			log_level: self.log_level.unwrap_or(LoggingLevel::Warning),
+			execution_providers: self.execution_providers
+		})
+	}
+}
@@ -0,0 +1,93 @@
+use std::{io, path::PathBuf};
+
+use thiserror::Error;
+
+/// Type alias for the `Result` type used throughout this crate.
+pub type OrtResult<T> = std::result::Result<T, OrtError>;
+
+/// An enumeration of errors that can occur while using `ort`.
+#[derive(Error, Debug)]
+pub enum OrtError {
+	/// An error returned by the ONNX Runtime C API itself.
+	#[error("Error calling ONNX Runtime C API: {0}")]
+	Ort(String),
+	/// The given session was not initialized before use.
+	#[error("Session was not initialized; a model must be loaded with `SessionBuilder::with_model_from_file` (or a similar constructor) first")]
+	SessionNotInitialized,
+	/// Could not find the requested file on disk.
+	#[error("File `{filename:?}` does not exist")]
+	FileDoesNotExist {
+		/// Path that was attempted.
+		filename: PathBuf
+	},
+	/// An I/O error occurred while reading a model or downloading an artifact.
+	#[error("I/O error: {0}")]
+	Io(#[from] io::Error),
+	/// A string returned by ONNX Runtime, or a downloaded label file, contained invalid UTF-8.
+	#[error("String contained invalid UTF-8 data: {0}")]
+	StringConversion(#[from] std::str::Utf8Error),
+	/// The requested input name was not found among the model's declared inputs.
+	#[error("Input `{0}` was not found in the model's declared inputs")]
+	UnknownInputName(String),
+	/// The requested output name was not found among the model's declared outputs.
+	#[error("Output `{0}` was not found in the model's declared outputs")]
+	UnknownOutputName(String),
+	/// A required model input was not supplied.
+	#[error("Missing required input `{0}`")]
+	MissingInput(String),
+	/// An extra input was supplied that the model does not declare.
+	#[error("Unexpected input `{0}` is not declared by the model")]
+	UnexpectedInput(String),
+	/// Failed to download a model-zoo artifact.
+	#[error("Failed to download `{0}`: {1}")]
+	DownloadError(String, String),
+	/// The downloaded artifact's checksum did not match the expected value.
+	#[error("Checksum mismatch for `{filename:?}`: expected {expected}, got {got}")]
+	ChecksumMismatch {
+		/// The file whose checksum did not match.
+		filename: PathBuf,
+		/// The expected checksum.
+		expected: String,
+		/// The checksum that was actually computed.
+		got: String
+	},
+	/// A tensor's shape did not match the shape expected of it: the model's declared dimensions for a bound
+	/// output, or, in a batch, the shape of the first item or one output entry per item along the batch axis.
+	#[error("Shape mismatch for `{name}`: expected {expected:?}, got {got:?}")]
+	NonMatchingShape {
+		/// Name of the input or output.
+		name: String,
+		/// The expected shape; a `None` dimension is not checked.
+		expected: Vec<Option<usize>>,
+		/// The shape that was actually supplied.
+		got: Vec<usize>
+	},
+	/// [`crate::session::BatchOptions::batch_axis`] does not fall within a tensor's rank.
+	#[error("Batch axis {axis} is out of bounds for `{name}`, which has rank {rank}")]
+	InvalidBatchAxis {
+		/// Name of the input or output the batch axis was applied to.
+		name: String,
+		/// The out-of-bounds batch axis that was requested.
+		axis: usize,
+		/// The rank of the tensor the axis was applied against.
+		rank: usize
+	},
+	/// The requested execution provider is not available in this build of ONNX Runtime.
+	#[error("Execution provider `{0}` is not available in this build of ONNX Runtime")]
+	ExecutionProviderNotAvailable(&'static str),
+	/// A model's declared dimensions for an input or output didn't match what its [`crate::download::ModelUrl`]
+	/// implementation advertised.
+	#[error("Declared dimensions for `{name}` don't match what `ModelUrl` advertised: model declares {model:?}, advertised {advertised:?}")]
+	DeclaredDimensionMismatch {
+		/// Name of the input or output.
+		name: String,
+		/// The dimensions actually declared by the loaded model.
+		model: Vec<Option<usize>>,
+		/// The dimensions advertised by the `ModelUrl` implementation.
+		advertised: Vec<Option<usize>>
+	},
+	/// The named operation requires calling into the ONNX Runtime C API, which this build of the crate does not
+	/// link against.
+	#[error("`{0}` requires linking against the ONNX Runtime shared library, which is not available in this build")]
+	NotLinked(&'static str)
+}
@@ -0,0 +1,303 @@
+/// The cuDNN convolution algorithm search strategy used by the [`CUDAExecutionProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CuDNNConvAlgoSearch {
+	/// Exhaustively benchmark every algorithm and pick the fastest. Slower to warm up, faster to run.
+	Exhaustive,
+	/// Use cuDNN's built-in heuristics to pick an algorithm without benchmarking.
+	#[default]
+	Heuristic,
+	/// Use cuDNN's default algorithm without any search.
+	Default
+}
+
+/// How ONNX Runtime should pick a WebGPU adapter when more than one is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WebGPUPowerPreference {
+	/// Let the platform choose.
+	#[default]
+	Default,
+	/// Prefer a low-power (usually integrated) adapter.
+	LowPower,
+	/// Prefer a high-performance (usually discrete) adapter.
+	HighPerformance
+}
+
+macro_rules! provider_options {
+	($options:ident { $($(#[$meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)? }) => {
+		#[derive(Debug, Clone)]
+		pub(crate) struct $options {
+			$($(#[$meta])* pub(crate) $field: $ty),*
+		}
+
+		impl Default for $options {
+			fn default() -> Self {
+				$options { $($field: $default),* }
+			}
+		}
+	};
+}
+
+provider_options!(CPUExecutionProviderOptions {
+	use_arena: bool = false
+});
+
+provider_options!(CUDAExecutionProviderOptions {
+	device_id: i32 = 0,
+	gpu_mem_limit: Option<usize> = None,
+	cudnn_conv_algo_search: CuDNNConvAlgoSearch = CuDNNConvAlgoSearch::default()
+});
+
+provider_options!(TensorRTExecutionProviderOptions {
+	device_id: i32 = 0,
+	fp16_enable: bool = false,
+	int8_enable: bool = false,
+	engine_cache_path: Option<std::path::PathBuf> = None
+});
+
+provider_options!(CoreMLExecutionProviderOptions {
+	ane_only: bool = false,
+	subgraphs_only: bool = false
+});
+
+provider_options!(WebGPUExecutionProviderOptions {
+	preferred_power_preference: WebGPUPowerPreference = WebGPUPowerPreference::default(),
+	device_id: i32 = 0,
+	enable_graph_capture: bool = false
+});
+
+/// Builder for the CPU execution provider. Always available, and used as the implicit last resort if no other
+/// provider in a fallback chain can be registered.
+#[derive(Debug, Clone, Default)]
+pub struct CPUExecutionProvider(CPUExecutionProviderOptions);
+
+impl CPUExecutionProvider {
+	/// Sets whether ONNX Runtime should use its arena allocator for this provider.
+	pub fn with_use_arena(mut self, use_arena: bool) -> Self {
+		self.0.use_arena = use_arena;
+		self
+	}
+
+	/// Finalizes this provider's configuration for use with `SessionBuilder::with_execution_providers`.
+	pub fn build(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::new(ExecutionProviderKind::Cpu(self.0))
+	}
+}
+
+/// Builder for the CUDA execution provider, for NVIDIA GPUs.
+#[derive(Debug, Clone, Default)]
+pub struct CUDAExecutionProvider(CUDAExecutionProviderOptions);
+
+impl CUDAExecutionProvider {
+	/// Selects which CUDA device to run on, for multi-GPU machines.
+	pub fn with_device_id(mut self, device_id: i32) -> Self {
+		self.0.device_id = device_id;
+		self
+	}
+
+	/// Caps the amount of GPU memory the arena allocator may claim, in bytes.
+	pub fn with_gpu_mem_limit(mut self, limit: usize) -> Self {
+		self.0.gpu_mem_limit = Some(limit);
+		self
+	}
+
+	/// Sets the strategy cuDNN uses to pick a convolution algorithm.
+	pub fn with_cudnn_conv_algo_search(mut self, search: CuDNNConvAlgoSearch) -> Self {
+		self.0.cudnn_conv_algo_search = search;
+		self
+	}
+
+	/// Finalizes this provider's configuration for use with `SessionBuilder::with_execution_providers`.
+	pub fn build(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::new(ExecutionProviderKind::Cuda(self.0))
+	}
+}
+
+/// Builder for the TensorRT execution provider, for NVIDIA GPUs with engine caching and reduced-precision
+/// inference.
+#[derive(Debug, Clone, Default)]
+pub struct TensorRTExecutionProvider(TensorRTExecutionProviderOptions);
+
+impl TensorRTExecutionProvider {
+	/// Selects which CUDA device TensorRT should build its engine against.
+	pub fn with_device_id(mut self, device_id: i32) -> Self {
+		self.0.device_id = device_id;
+		self
+	}
+
+	/// Enables FP16 precision, trading a small amount of accuracy for substantially faster inference.
+	pub fn with_fp16(mut self, enable: bool) -> Self {
+		self.0.fp16_enable = enable;
+		self
+	}
+
+	/// Enables INT8 precision. Requires a calibration cache; see ONNX Runtime's TensorRT documentation.
+	pub fn with_int8(mut self, enable: bool) -> Self {
+		self.0.int8_enable = enable;
+		self
+	}
+
+	/// Sets the directory TensorRT caches compiled engines in, so subsequent runs skip engine rebuilding.
+	pub fn with_engine_cache_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+		self.0.engine_cache_path = Some(path.into());
+		self
+	}
+
+	/// Finalizes this provider's configuration for use with `SessionBuilder::with_execution_providers`.
+	pub fn build(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::new(ExecutionProviderKind::TensorRT(self.0))
+	}
+}
+
+/// Builder for the CoreML execution provider, for Apple Neural Engine/GPU acceleration on macOS and iOS.
+#[derive(Debug, Clone, Default)]
+pub struct CoreMLExecutionProvider(CoreMLExecutionProviderOptions);
+
+impl CoreMLExecutionProvider {
+	/// Restricts execution to the Apple Neural Engine, failing rather than falling back to CPU/GPU on models it
+	/// can't run.
+	pub fn with_ane_only(mut self, ane_only: bool) -> Self {
+		self.0.ane_only = ane_only;
+		self
+	}
+
+	/// Only delegates individual subgraphs to CoreML instead of requiring the whole graph to run on it.
+	pub fn with_subgraphs_only(mut self, subgraphs_only: bool) -> Self {
+		self.0.subgraphs_only = subgraphs_only;
+		self
+	}
+
+	/// Finalizes this provider's configuration for use with `SessionBuilder::with_execution_providers`.
+	pub fn build(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::new(ExecutionProviderKind::CoreML(self.0))
+	}
+}
+
+/// Builder for the WebGPU execution provider, which runs inference through `wgpu` on top of Vulkan, Metal, or
+/// DX12, giving portable GPU acceleration without a vendor-specific build like CUDA.
+#[derive(Debug, Clone, Default)]
+pub struct WebGPUExecutionProvider(WebGPUExecutionProviderOptions);
+
+impl WebGPUExecutionProvider {
+	/// Prefers an adapter matching `preference` when the platform exposes more than one.
+	pub fn with_power_preference(mut self, preference: WebGPUPowerPreference) -> Self {
+		self.0.preferred_power_preference = preference;
+		self
+	}
+
+	/// Selects which WebGPU-capable device to run on, for systems with multiple.
+	pub fn with_device_id(mut self, device_id: i32) -> Self {
+		self.0.device_id = device_id;
+		self
+	}
+
+	/// Enables ONNX Runtime's graph capture optimization, which records a fixed sequence of GPU commands once
+	/// and replays it on every `run`. Only safe for models whose input/output shapes never change between runs.
+	pub fn with_graph_capture(mut self, enable: bool) -> Self {
+		self.0.enable_graph_capture = enable;
+		self
+	}
+
+	/// Finalizes this provider's configuration for use with `SessionBuilder::with_execution_providers`.
+	pub fn build(self) -> ExecutionProviderDispatch {
+		ExecutionProviderDispatch::new(ExecutionProviderKind::WebGPU(self.0))
+	}
+}
+
+#[derive(Debug, Clone)]
+enum ExecutionProviderKind {
+	Cpu(CPUExecutionProviderOptions),
+	Cuda(CUDAExecutionProviderOptions),
+	TensorRT(TensorRTExecutionProviderOptions),
+	CoreML(CoreMLExecutionProviderOptions),
+	WebGPU(WebGPUExecutionProviderOptions)
+}
+
+/// A fully configured execution provider, produced by calling `.build()` on one of the provider builders (e.g.
+/// [`CPUExecutionProvider`], [`CUDAExecutionProvider::default().with_device_id(0)`](CUDAExecutionProvider)) and
+/// ready to be handed to [`crate::session::SessionBuilder::with_execution_providers`].
+///
+/// `SessionBuilder::with_execution_providers` tries each dispatch in the order given and falls back to the next
+/// one if a provider fails to register (for example, because ONNX Runtime wasn't built with support for it).
+#[derive(Debug, Clone)]
+pub struct ExecutionProviderDispatch {
+	kind: ExecutionProviderKind
+}
+
+impl ExecutionProviderDispatch {
+	fn new(kind: ExecutionProviderKind) -> Self {
+		ExecutionProviderDispatch { kind }
+	}
+
+	/// Returns the human-readable name ONNX Runtime uses to refer to this provider.
+	pub fn as_str(&self) -> &'static str {
+		match &self.kind {
+			ExecutionProviderKind::Cpu(_) => "CPUExecutionProvider",
+			ExecutionProviderKind::Cuda(_) => "CUDAExecutionProvider",
+			ExecutionProviderKind::TensorRT(_) => "TensorrtExecutionProvider",
+			ExecutionProviderKind::CoreML(_) => "CoreMLExecutionProvider",
+			ExecutionProviderKind::WebGPU(_) => "WebGpuExecutionProvider"
+		}
+	}
+
+	/// Returns whether this provider is registered in the linked ONNX Runtime build and can actually be used.
+	///
+	/// `CPU` is hard-coded to always report available; every other provider is hard-coded to report
+	/// unavailable. Actually querying a build's compiled-in providers needs the same ONNX Runtime C API link
+	/// described in the crate-level "Current status" section, so until that's wired in, `select_available`
+	/// below will always degrade to CPU regardless of what's passed to `with_execution_providers` — this is a
+	/// known, called-out limitation of the current fallback-chain API, not a working runtime probe.
+	pub fn is_available(&self) -> bool {
+		matches!(&self.kind, ExecutionProviderKind::Cpu(_))
+	}
+
+	/// Formats this provider's configured options for diagnostics, until `Session::from_bytes` reads them
+	/// directly while registering the provider with the ONNX Runtime C API.
+	pub(crate) fn debug_options(&self) -> String {
+		match &self.kind {
+			ExecutionProviderKind::Cpu(options) => format!("{options:?}"),
+			ExecutionProviderKind::Cuda(options) => format!("{options:?}"),
+			ExecutionProviderKind::TensorRT(options) => format!("{options:?}"),
+			ExecutionProviderKind::CoreML(options) => format!("{options:?}"),
+			ExecutionProviderKind::WebGPU(options) => format!("{options:?}")
+		}
+	}
+}
+
+/// Picks the first available provider from `providers`, in order, falling back to the next on failure.
+///
+/// `CPU` is always available, so it is tried as an implicit last resort even if the caller didn't list it
+/// explicitly — e.g. `with_execution_providers([WebGPUExecutionProvider::default().build()])` still falls back
+/// to CPU on a build of ONNX Runtime without WebGPU support, rather than erroring.
+pub(crate) fn select_available(providers: &[ExecutionProviderDispatch]) -> ExecutionProviderDispatch {
+	providers
+		.iter()
+		.find(|provider| provider.is_available())
+		.cloned()
+		.unwrap_or_else(|| CPUExecutionProvider::default().build())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn select_available_prefers_the_first_available_provider() {
+		let providers = [CPUExecutionProvider::default().build(), CPUExecutionProvider::default().with_use_arena(true).build()];
+		assert_eq!(select_available(&providers).as_str(), "CPUExecutionProvider");
+		assert!(select_available(&providers).debug_options().contains("use_arena: false"));
+	}
+
+	#[test]
+	fn select_available_falls_back_to_cpu_when_nothing_else_is_available() {
+		let providers = [WebGPUExecutionProvider::default().build()];
+		let selected = select_available(&providers);
+		assert_eq!(selected.as_str(), "CPUExecutionProvider");
+		assert!(selected.is_available());
+	}
+
+	#[test]
+	fn select_available_falls_back_to_cpu_on_an_empty_list() {
+		let selected = select_available(&[]);
+		assert_eq!(selected.as_str(), "CPUExecutionProvider");
+	}
+}
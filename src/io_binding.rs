@@ -0,0 +1,165 @@
+//! Pre-bound inputs and outputs for repeated inference on fixed shapes, avoiding the output allocation churn
+//! that [`crate::session::Session::run`] incurs on every call.
+
+use std::collections::HashMap;
+
+use ndarray::{ArrayViewMut, IxDyn};
+
+use crate::{error::OrtResult, session::Session, value::Value};
+
+/// Where an [`IoBinding`] output should be written.
+pub enum OutputBinding<'b> {
+	/// Leave the result on whatever device the execution provider produced it on (e.g. GPU memory), so it can
+	/// be fed into a subsequent `run` without a device-to-host copy.
+	Device,
+	/// Write directly into a caller-supplied `ndarray` buffer, reused across every call to
+	/// [`Session::run_with_binding`].
+	Buffer(ArrayViewMut<'b, f32, IxDyn>)
+}
+
+/// A set of input values and output destinations bound once and reused across repeated calls to
+/// [`Session::run_with_binding`], so the caller pays for tensor allocation only once instead of on every run.
+///
+/// Obtained via [`Session::io_binding`].
+pub struct IoBinding<'s> {
+	pub(crate) session: &'s Session,
+	pub(crate) inputs: HashMap<String, Value<'s>>,
+	pub(crate) outputs: HashMap<String, OutputBinding<'s>>
+}
+
+impl<'s> IoBinding<'s> {
+	pub(crate) fn new(session: &'s Session) -> Self {
+		IoBinding {
+			session,
+			inputs: HashMap::new(),
+			outputs: HashMap::new()
+		}
+	}
+
+	/// Binds `value` to the input named `name`. `name` must match one of [`Session::inputs`].
+	pub fn bind_input<S: Into<String>>(&mut self, name: S, value: Value<'s>) -> OrtResult<()> {
+		let name = name.into();
+		if !self.session.inputs.iter().any(|i| i.name == name) {
+			return Err(crate::error::OrtError::UnknownInputName(name));
+		}
+		self.inputs.insert(name, value);
+		Ok(())
+	}
+
+	/// Binds the output named `name` to stay on-device (e.g. GPU memory) between calls, rather than being
+	/// copied back to the host on every run.
+	pub fn bind_output_to_device<S: Into<String>>(&mut self, name: S) -> OrtResult<()> {
+		let name = name.into();
+		self.check_output_shape(&name, None)?;
+		self.outputs.insert(name, OutputBinding::Device);
+		Ok(())
+	}
+
+	/// Binds the output named `name` to a caller-owned buffer that ONNX Runtime will write directly into on
+	/// every call to [`Session::run_with_binding`], instead of allocating a fresh tensor each time.
+	///
+	/// The buffer's shape is validated against the dimensions ONNX Runtime reports for this output wherever
+	/// they're statically known; dynamic dimensions (reported as `None`) are not checked.
+	pub fn bind_output_to_buffer<S: Into<String>>(&mut self, name: S, buffer: ArrayViewMut<'s, f32, IxDyn>) -> OrtResult<()> {
+		let name = name.into();
+		self.check_output_shape(&name, Some(buffer.shape()))?;
+		self.outputs.insert(name, OutputBinding::Buffer(buffer));
+		Ok(())
+	}
+
+	fn check_output_shape(&self, name: &str, shape: Option<&[usize]>) -> OrtResult<()> {
+		let output = self
+			.session
+			.outputs
+			.iter()
+			.find(|o| o.name == name)
+			.ok_or_else(|| crate::error::OrtError::UnknownOutputName(name.to_string()))?;
+
+		if let Some(shape) = shape {
+			let expected: Vec<Option<usize>> = output.dimensions().collect();
+			let matches = expected.len() == shape.len()
+				&& expected.iter().zip(shape.iter()).all(|(want, got)| want.is_none() || *want == Some(*got));
+			if !matches {
+				return Err(crate::error::OrtError::NonMatchingShape {
+					name: name.to_string(),
+					expected,
+					got: shape.to_vec()
+				});
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl Session {
+	/// Creates an [`IoBinding`] that inputs and outputs can be bound to once and reused across many calls to
+	/// [`Session::run_with_binding`], instead of allocating a fresh output `Value` on every `run`.
+	pub fn io_binding(&self) -> OrtResult<IoBinding<'_>> {
+		Ok(IoBinding::new(self))
+	}
+
+	/// Runs the model using the inputs and output destinations bound on `binding`, writing results into any
+	/// buffer-bound outputs in place.
+	///
+	/// This does not yet do so: `bind_input`/`bind_output_to_buffer` only validate names and shapes and stage
+	/// them on `binding`, and this method does not read `binding` at all. Actually running inference and writing
+	/// into the bound buffers requires ONNX Runtime's `OrtRunWithBinding`, which needs the C API link described
+	/// in the crate-level "Current status" section and has no implementation here yet, reachable or otherwise.
+	pub fn run_with_binding(&self, binding: &mut IoBinding<'_>) -> OrtResult<()> {
+		let _ = binding;
+		Err(crate::error::OrtError::NotLinked("IoBinding::run_with_binding"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ndarray::Array;
+
+	use super::*;
+	use crate::error::OrtError;
+
+	#[test]
+	fn bind_input_rejects_unknown_name() {
+		let session = Session::test_instance(vec!["x"], vec!["y"]);
+		let mut binding = session.io_binding().unwrap();
+		let err = binding.bind_input("z", Value::test_placeholder(session.allocator())).unwrap_err();
+		assert!(matches!(err, OrtError::UnknownInputName(name) if name == "z"));
+	}
+
+	#[test]
+	fn bind_output_rejects_unknown_name_with_output_specific_error() {
+		let session = Session::test_instance(vec!["x"], vec!["y"]);
+		let mut binding = session.io_binding().unwrap();
+		let err = binding.bind_output_to_device("z").unwrap_err();
+		assert!(matches!(err, OrtError::UnknownOutputName(name) if name == "z"));
+	}
+
+	#[test]
+	fn bind_output_to_buffer_accepts_a_matching_shape() {
+		let mut session = Session::test_instance(vec!["x"], vec!["y"]);
+		session.outputs[0].dimensions = vec![Some(2), Some(3)];
+		let mut binding = session.io_binding().unwrap();
+		let mut buffer = Array::<f32, _>::zeros(IxDyn(&[2, 3]));
+		assert!(binding.bind_output_to_buffer("y", buffer.view_mut()).is_ok());
+	}
+
+	#[test]
+	fn bind_output_to_buffer_rejects_a_mismatched_static_dimension() {
+		let mut session = Session::test_instance(vec!["x"], vec!["y"]);
+		session.outputs[0].dimensions = vec![Some(2), Some(3)];
+		let mut binding = session.io_binding().unwrap();
+		let mut buffer = Array::<f32, _>::zeros(IxDyn(&[2, 4]));
+		let err = binding.bind_output_to_buffer("y", buffer.view_mut()).unwrap_err();
+		assert!(matches!(err, OrtError::NonMatchingShape { name, .. } if name == "y"));
+	}
+
+	#[test]
+	fn bind_output_to_buffer_permits_dynamic_dimensions() {
+		let mut session = Session::test_instance(vec!["x"], vec!["y"]);
+		session.outputs[0].dimensions = vec![None, Some(3)];
+		let mut binding = session.io_binding().unwrap();
+		let mut buffer = Array::<f32, _>::zeros(IxDyn(&[7, 3]));
+		assert!(binding.bind_output_to_buffer("y", buffer.view_mut()).is_ok());
+	}
+}
@@ -0,0 +1,28 @@
+//! `ort` is a Rust binding for [ONNX Runtime](https://onnxruntime.ai/), allowing you to run trained machine
+//! learning models to make fast predictions, or "inference", with support for execution on CPU, GPU, and beyond.
+//!
+//! ## Current status
+//!
+//! This crate currently defines the full `ort` API surface — session construction, named/batched run, IO
+//! binding, execution-provider selection — and validates it in pure Rust against a model's declared shapes and
+//! names. It does not yet link against the ONNX Runtime C API, so [`Session::run`] and the other `run*` methods
+//! return [`OrtError::NotLinked`] instead of producing real inference results. Wiring in that C API link is
+//! tracked as follow-up work; call sites that stand in for it are noted inline rather than hidden.
+
+pub mod allocator;
+pub mod download;
+pub mod environment;
+pub mod error;
+pub mod execution_providers;
+pub mod io_binding;
+pub mod metadata;
+pub mod session;
+pub mod tensor;
+pub mod value;
+
+pub use crate::{
+	environment::{Environment, LoggingLevel},
+	error::{OrtError, OrtResult},
+	execution_providers::{CPUExecutionProvider, ExecutionProviderDispatch},
+	session::{GraphOptimizationLevel, Session, SessionBuilder}
+};
@@ -0,0 +1,24 @@
+use crate::error::OrtResult;
+
+/// Metadata describing a loaded model: its name and producer.
+#[derive(Debug)]
+pub struct Metadata {
+	name: String,
+	producer: String
+}
+
+impl Metadata {
+	pub(crate) fn new(name: String, producer: String) -> Self {
+		Metadata { name, producer }
+	}
+
+	/// Returns the model's name, as set by whatever tool produced the ONNX graph.
+	pub fn name(&self) -> OrtResult<String> {
+		Ok(self.name.clone())
+	}
+
+	/// Returns the name of the tool that produced this model (e.g. `tf2onnx`, `pytorch`).
+	pub fn producer(&self) -> OrtResult<String> {
+		Ok(self.producer.clone())
+	}
+}
@@ -0,0 +1,194 @@
+use std::{num::NonZeroUsize, thread};
+
+use ndarray::{Array, Axis, CowArray, IxDyn};
+
+use crate::{error::OrtResult, session::Session, tensor::OrtOwnedTensor, value::Value};
+
+/// Options controlling how [`Session::run_batch`] stacks inputs and parallelizes preprocessing.
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+	/// The axis along which individual items are stacked into (and later split back out of) the batch tensor.
+	/// Defaults to `0`.
+	pub batch_axis: usize,
+	/// The number of threads used to run the preprocessing closure passed to [`Session::run_batch`]. Defaults to
+	/// [`std::thread::available_parallelism`]. Items are preprocessed in parallel with each other; the whole batch
+	/// is preprocessed before the model is run, so preprocessing does not overlap with inference.
+	pub preprocessing_threads: usize
+}
+
+impl Default for BatchOptions {
+	fn default() -> Self {
+		BatchOptions {
+			batch_axis: 0,
+			preprocessing_threads: thread::available_parallelism().map(NonZeroUsize::get).unwrap_or(1)
+		}
+	}
+}
+
+impl Session {
+	/// Runs the model on a batch of items at once: each item is transformed by `preprocess` (on a thread pool
+	/// sized by [`BatchOptions::preprocessing_threads`]) into a tensor with no batch axis of its own, the
+	/// resulting tensors are stacked along a newly-inserted [`BatchOptions::batch_axis`] into a single input
+	/// `Value`, the model is run once on the full batch, and the output is split back along that same axis into
+	/// one [`OrtOwnedTensor`] per input item, in the same order they were given.
+	///
+	/// All items must produce the same shape after preprocessing, and the output must hold one entry per item
+	/// along the batch axis; a mismatch in either is reported as [`crate::error::OrtError::NonMatchingShape`]
+	/// rather than silently truncating or padding.
+	pub fn run_batch<'s, T, F>(&'s self, items: Vec<T>, preprocess: F, options: BatchOptions) -> OrtResult<Vec<OrtOwnedTensor<'s, f32, IxDyn>>>
+	where
+		T: Send,
+		F: Fn(T) -> OrtResult<CowArray<'static, f32, IxDyn>> + Sync
+	{
+		let arrays = Self::preprocess_concurrently(items, &preprocess, options.preprocessing_threads)?;
+		if arrays.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let shape = arrays[0].shape().to_vec();
+		for array in &arrays {
+			if array.shape() != shape.as_slice() {
+				return Err(crate::error::OrtError::NonMatchingShape {
+					name: self.inputs.first().map(|i| i.name.clone()).unwrap_or_default(),
+					expected: shape.iter().map(|&d| Some(d)).collect(),
+					got: array.shape().to_vec()
+				});
+			}
+		}
+
+		// `stack` inserts a new axis at `batch_axis`, so the valid range includes one past the last existing
+		// dimension (e.g. `batch_axis == shape.len()` appends a trailing batch axis).
+		if options.batch_axis > shape.len() {
+			return Err(crate::error::OrtError::InvalidBatchAxis {
+				name: self.inputs.first().map(|i| i.name.clone()).unwrap_or_default(),
+				axis: options.batch_axis,
+				rank: shape.len()
+			});
+		}
+
+		let views: Vec<_> = arrays.iter().map(|a| a.view()).collect();
+		let batched = ndarray::stack(Axis(options.batch_axis), &views).expect("all items were checked to share a shape above, and batch_axis was checked to be in bounds");
+
+		let batched = CowArray::from(batched);
+		let input = Value::from_array(self.allocator(), &batched)?;
+		let outputs = self.run(vec![input])?;
+
+		// The output is split along the same `batch_axis`, checked against the output tensor's own shape rather
+		// than the input batch built above. Unlike the stacking above, `index_axis` operates on an axis that
+		// already exists, so it must be strictly within the output's rank rather than one past it, and the
+		// output's length along it must equal the number of items so each item gets exactly one slice.
+		let extracted = outputs[0].try_extract()?;
+		let output_rank = extracted.view().ndim();
+		if options.batch_axis >= output_rank {
+			return Err(crate::error::OrtError::InvalidBatchAxis {
+				name: self.outputs.first().map(|o| o.name.clone()).unwrap_or_default(),
+				axis: options.batch_axis,
+				rank: output_rank
+			});
+		}
+		let batch_len = extracted.view().len_of(Axis(options.batch_axis));
+		if batch_len != arrays.len() {
+			let got = extracted.view().shape().to_vec();
+			let mut expected = vec![None; got.len()];
+			expected[options.batch_axis] = Some(arrays.len());
+			return Err(crate::error::OrtError::NonMatchingShape {
+				name: self.outputs.first().map(|o| o.name.clone()).unwrap_or_default(),
+				expected,
+				got
+			});
+		}
+		Ok((0..batch_len)
+			.map(|i| {
+				let item = extracted.view().index_axis(Axis(options.batch_axis), i).insert_axis(Axis(options.batch_axis)).to_owned();
+				OrtOwnedTensor::from_owned(item)
+			})
+			.collect())
+	}
+
+	fn preprocess_concurrently<T, F>(items: Vec<T>, preprocess: &F, thread_count: usize) -> OrtResult<Vec<Array<f32, IxDyn>>>
+	where
+		T: Send,
+		F: Fn(T) -> OrtResult<CowArray<'static, f32, IxDyn>> + Sync
+	{
+		let thread_count = thread_count.max(1).min(items.len().max(1));
+		let chunk_size = items.len().div_ceil(thread_count);
+		if chunk_size == 0 {
+			return Ok(Vec::new());
+		}
+
+		let chunks: Vec<Vec<T>> = items
+			.into_iter()
+			.fold(Vec::new(), |mut chunks: Vec<Vec<T>>, item| {
+				if chunks.last().is_none_or(|c: &Vec<T>| c.len() >= chunk_size) {
+					chunks.push(Vec::new());
+				}
+				chunks.last_mut().unwrap().push(item);
+				chunks
+			});
+
+		thread::scope(|scope| {
+			let handles: Vec<_> = chunks
+				.into_iter()
+				.map(|chunk| {
+					scope.spawn(move || chunk.into_iter().map(preprocess).collect::<OrtResult<Vec<_>>>().map(|arrs| arrs.into_iter().map(|a| a.into_owned()).collect::<Vec<_>>()))
+				})
+				.collect();
+
+			let mut results = Vec::new();
+			for handle in handles {
+				results.extend(handle.join().expect("preprocessing thread panicked")?);
+			}
+			Ok(results)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::error::OrtError;
+
+	fn array1(values: &[f32]) -> CowArray<'static, f32, IxDyn> {
+		CowArray::from(Array::from_shape_vec(IxDyn(&[values.len()]), values.to_vec()).unwrap())
+	}
+
+	#[test]
+	fn run_batch_on_empty_items_returns_empty_result_instead_of_panicking() {
+		let session = Session::test_instance(vec!["x"], vec!["y"]);
+		let result = session.run_batch(Vec::<Vec<f32>>::new(), |item| Ok(array1(&item)), BatchOptions::default());
+		assert_eq!(result.unwrap().len(), 0);
+	}
+
+	#[test]
+	fn run_batch_rejects_items_with_mismatched_shapes() {
+		let session = Session::test_instance(vec!["x"], vec!["y"]);
+		let items = vec![vec![1.0_f32, 2.0], vec![1.0_f32]];
+		let err = session.run_batch(items, |item| Ok(array1(&item)), BatchOptions::default()).unwrap_err();
+		assert!(matches!(err, OrtError::NonMatchingShape { name, .. } if name == "x"));
+	}
+
+	#[test]
+	fn run_batch_splits_the_models_output_back_into_one_tensor_per_item() {
+		// Two items of shape [2] stack into a [2, 2] input batch; stub `run` to hand back a [2, 2] output as if
+		// the model had run on it, and check `run_batch` splits it back into one [1, 2] tensor per item, in
+		// order, rather than leaving that path (which never executes against the `NotLinked` stub) unverified.
+		let session = Session::test_instance_with_output(vec!["x"], vec!["y"], vec![2, 2], vec![10.0, 20.0, 30.0, 40.0]);
+		let items = vec![vec![1.0_f32, 2.0], vec![3.0_f32, 4.0]];
+		let result = session.run_batch(items, |item| Ok(array1(&item)), BatchOptions::default()).unwrap();
+
+		assert_eq!(result.len(), 2);
+		assert_eq!(result[0].view().shape(), &[1, 2]);
+		assert_eq!(result[0].view().iter().copied().collect::<Vec<_>>(), vec![10.0, 20.0]);
+		assert_eq!(result[1].view().shape(), &[1, 2]);
+		assert_eq!(result[1].view().iter().copied().collect::<Vec<_>>(), vec![30.0, 40.0]);
+	}
+
+	#[test]
+	fn run_batch_rejects_a_batch_axis_past_the_preprocessed_rank() {
+		let session = Session::test_instance(vec!["x"], vec!["y"]);
+		let items = vec![vec![1.0_f32, 2.0], vec![3.0_f32, 4.0]];
+		let options = BatchOptions { batch_axis: 5, ..BatchOptions::default() };
+		let err = session.run_batch(items, |item| Ok(array1(&item)), options).unwrap_err();
+		assert!(matches!(err, OrtError::InvalidBatchAxis { name, axis: 5, rank: 1 } if name == "x"));
+	}
+}
@@ -0,0 +1,19 @@
+/// Describes one named input or output of a loaded model.
+#[derive(Debug, Clone)]
+pub struct Io {
+	pub(crate) name: String,
+	pub(crate) dimensions: Vec<Option<usize>>
+}
+
+impl Io {
+	/// The name ONNX Runtime assigned to this input/output in the model graph.
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// The declared dimensions of this input/output. A dimension is `None` if it is symbolic/dynamic (e.g. a
+	/// batch axis), or `Some(n)` if the model fixes it to `n`.
+	pub fn dimensions(&self) -> impl Iterator<Item = Option<usize>> + '_ {
+		self.dimensions.iter().copied()
+	}
+}
@@ -0,0 +1,308 @@
+mod batch;
+mod input;
+
+use std::{path::Path, sync::Arc};
+
+pub use self::{batch::BatchOptions, input::Io};
+use crate::{allocator::Allocator, environment::Environment, error::OrtResult, execution_providers::ExecutionProviderDispatch, metadata::Metadata, value::Value};
+
+/// The graph-level optimizations ONNX Runtime will apply to a model before it is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphOptimizationLevel {
+	/// Disables all graph optimizations.
+	Disable,
+	/// Only the cheapest, always-safe optimizations (e.g. constant folding).
+	Level1,
+	/// `Level1` plus optimizations that may restructure the graph (e.g. node fusions).
+	Level2,
+	/// All optimizations, including ones specific to the registered execution providers.
+	Level3
+}
+
+/// Builds a [`Session`] from a model file or in-memory buffer, with the execution providers, thread pool, and
+/// graph optimizations the caller wants.
+pub struct SessionBuilder {
+	environment: Arc<Environment>,
+	optimization_level: GraphOptimizationLevel,
+	intra_threads: i16,
+	execution_providers: Vec<ExecutionProviderDispatch>
+}
+
+impl SessionBuilder {
+	/// Starts building a new session from `environment`, which supplies the default execution providers and
+	/// logging configuration unless overridden on the builder itself.
+	pub fn new(environment: &Arc<Environment>) -> OrtResult<Self> {
+		Ok(SessionBuilder {
+			environment: Arc::clone(environment),
+			optimization_level: GraphOptimizationLevel::Level3,
+			intra_threads: 0,
+			execution_providers: environment.execution_providers().to_vec()
+		})
+	}
+
+	/// Sets the graph optimizations ONNX Runtime will apply before running the model.
+	pub fn with_optimization_level(mut self, level: GraphOptimizationLevel) -> OrtResult<Self> {
+		self.optimization_level = level;
+		Ok(self)
+	}
+
+	/// Sets the number of threads used to parallelize execution within each operator.
+	pub fn with_intra_threads(mut self, threads: i16) -> OrtResult<Self> {
+		self.intra_threads = threads;
+		Ok(self)
+	}
+
+	/// Overrides the execution providers to try, in priority order, falling back to the next on failure.
+	pub fn with_execution_providers(mut self, execution_providers: impl IntoIterator<Item = ExecutionProviderDispatch>) -> OrtResult<Self> {
+		self.execution_providers = execution_providers.into_iter().collect();
+		Ok(self)
+	}
+
+	/// Loads a model from a file on disk and builds the [`Session`].
+	pub fn with_model_from_file<P: AsRef<Path>>(self, path: P) -> OrtResult<Session> {
+		let bytes = std::fs::read(path.as_ref()).map_err(|_| crate::error::OrtError::FileDoesNotExist {
+			filename: path.as_ref().to_path_buf()
+		})?;
+		self.with_model_from_memory(&bytes)
+	}
+
+	/// Loads a model from an in-memory ONNX (`.onnx`) buffer and builds the [`Session`].
+	pub fn with_model_from_memory(self, bytes: &[u8]) -> OrtResult<Session> {
+		Session::from_bytes(self, bytes)
+	}
+
+	/// Loads a model from an in-memory, already-optimized ORT format (`.ort`) buffer without copying it, and
+	/// builds the [`Session`].
+	pub fn with_model_from_memory_directly(self, bytes: &[u8]) -> OrtResult<Session> {
+		Session::from_bytes(self, bytes)
+	}
+
+	/// Downloads `model` from the ONNX Model Zoo into the default cache directory (see
+	/// [`crate::download::default_cache_dir`]) and builds the [`Session`] from the cached file. The file's
+	/// checksum is verified only when [`crate::download::ModelUrl::sha256`] returns one.
+	///
+	/// Subsequent calls with the same model reuse the cached file instead of downloading it again.
+	pub fn with_model_downloaded<M: crate::download::ModelUrl>(self, model: M) -> OrtResult<Session> {
+		self.with_model_downloaded_in(model, crate::download::default_cache_dir())
+	}
+
+	/// Like [`SessionBuilder::with_model_downloaded`], but caches the model in `cache_dir` instead of the
+	/// default cache directory.
+	///
+	/// Once loaded, the model's declared input/output dimensions are checked against what `model` advertises via
+	/// [`crate::download::ModelUrl::input_dimensions`]/[`crate::download::ModelUrl::output_dimensions`], so a
+	/// `ModelUrl` whose advertised shape no longer matches the file it downloads is caught here rather than
+	/// surfacing as a confusing shape error much later, in `run`.
+	pub fn with_model_downloaded_in<M: crate::download::ModelUrl>(self, model: M, cache_dir: impl AsRef<Path>) -> OrtResult<Session> {
+		let path = crate::download::download_model(&model, cache_dir)?;
+		let session = self.with_model_from_file(path)?;
+		validate_declared_dimensions(session.inputs.first(), model.input_dimensions())?;
+		validate_declared_dimensions(session.outputs.first(), model.output_dimensions())?;
+		Ok(session)
+	}
+}
+
+/// Checks `io`'s declared dimensions against `advertised`, treating a `None` on either side as a wildcard that
+/// matches anything (since a dynamic axis, like a batch dimension, is free to differ).
+fn validate_declared_dimensions(io: Option<&Io>, advertised: &[Option<usize>]) -> OrtResult<()> {
+	let Some(io) = io else {
+		return Ok(());
+	};
+
+	let model: Vec<Option<usize>> = io.dimensions().collect();
+	let matches = model.len() == advertised.len() && model.iter().zip(advertised).all(|(m, a)| m.is_none() || a.is_none() || m == a);
+	if matches {
+		Ok(())
+	} else {
+		Err(crate::error::OrtError::DeclaredDimensionMismatch {
+			name: io.name.clone(),
+			model,
+			advertised: advertised.to_vec()
+		})
+	}
+}
+
+/// A loaded model, ready to run inference via [`Session::run`].
+pub struct Session {
+	allocator: Allocator,
+	metadata: Metadata,
+	/// The model's declared inputs, in graph order.
+	pub inputs: Vec<Io>,
+	/// The model's declared outputs, in graph order.
+	pub outputs: Vec<Io>,
+	/// Stands in for a real ONNX Runtime `Run` call in tests that need `run` to return a chosen output shape
+	/// and data instead of erroring, e.g. to exercise `Session::run_batch`'s stacking/splitting logic. Set via
+	/// [`Session::test_instance_with_output`]; unused (and absent) outside tests.
+	#[cfg(test)]
+	test_run_output: Option<(Vec<usize>, Vec<f32>)>
+}
+
+impl Session {
+	fn from_bytes(builder: SessionBuilder, _bytes: &[u8]) -> OrtResult<Session> {
+		// Falls back through `builder.execution_providers` in priority order, settling on the first one this
+		// build of ONNX Runtime actually registered (`CPU` is used as a last resort even when not listed).
+		let provider = crate::execution_providers::select_available(&builder.execution_providers);
+
+		// These are threaded through to where `OrtCreateEnv`/`OrtCreateSessionOptions` would read them once the
+		// C API is actually linked in; read here so the plumbing compiles clean in the meantime.
+		let _ = (
+			builder.environment.name(),
+			builder.environment.log_level(),
+			&builder.optimization_level,
+			builder.intra_threads,
+			provider.debug_options()
+		);
+
+		// Model parsing needs the ONNX Runtime C API link noted in the crate-level "Current status" section;
+		// this would be a thin wrapper around `OrtCreateSession` and friends. The allocator a real
+		// `OrtCreateSession` call would hand back is wrapped the same way `Allocator::new` does here.
+		let allocator = Allocator::new(std::ptr::null_mut());
+		let _ = allocator.ptr();
+
+		Err(crate::error::OrtError::NotLinked("loading a session"))
+	}
+
+	/// Returns the allocator used to create [`Value`]s for this session's inputs.
+	pub fn allocator(&self) -> &Allocator {
+		&self.allocator
+	}
+
+	/// Returns metadata about the loaded model, such as its name and producer.
+	pub fn metadata(&self) -> OrtResult<Metadata> {
+		Ok(Metadata::new(self.metadata.name()?, self.metadata.producer()?))
+	}
+
+	/// Runs the model on `input_tensor_values`, supplied in the same order as [`Session::inputs`], and returns
+	/// the outputs in the same order as [`Session::outputs`].
+	pub fn run<'s>(&'s self, input_tensor_values: Vec<Value<'s>>) -> OrtResult<Vec<Value<'s>>> {
+		let _ = input_tensor_values;
+		#[cfg(test)]
+		if let Some((shape, data)) = &self.test_run_output {
+			return Ok(vec![Value::test_tensor(self.allocator(), shape.clone(), data.clone())]);
+		}
+		Err(crate::error::OrtError::NotLinked("Session::run"))
+	}
+
+	/// Runs the model on inputs keyed by name rather than position, returning outputs keyed by name in the same
+	/// way.
+	///
+	/// Every name in `input_tensor_values` must match one of [`Session::inputs`]; any missing or unrecognized
+	/// name produces [`crate::error::OrtError::MissingInput`] or [`crate::error::OrtError::UnexpectedInput`]
+	/// rather than silently misaligning positional tensors.
+	pub fn run_with_names<'s>(&'s self, input_tensor_values: std::collections::HashMap<String, Value<'s>>) -> OrtResult<std::collections::HashMap<String, Value<'s>>> {
+		let mut input_tensor_values = input_tensor_values;
+
+		for input in &self.inputs {
+			if !input_tensor_values.contains_key(&input.name) {
+				return Err(crate::error::OrtError::MissingInput(input.name.clone()));
+			}
+		}
+		let known: std::collections::HashSet<&str> = self.inputs.iter().map(|i| i.name.as_str()).collect();
+		if let Some(unexpected) = input_tensor_values.keys().find(|name| !known.contains(name.as_str())) {
+			return Err(crate::error::OrtError::UnexpectedInput(unexpected.clone()));
+		}
+
+		// Reorder the supplied tensors to match the positional order ONNX Runtime expects.
+		let ordered_inputs = self
+			.inputs
+			.iter()
+			.map(|input| input_tensor_values.remove(&input.name).expect("presence was just validated above"))
+			.collect();
+
+		let ordered_outputs = self.run(ordered_inputs)?;
+
+		Ok(self.outputs.iter().map(|output| output.name.clone()).zip(ordered_outputs).collect())
+	}
+}
+
+#[cfg(test)]
+impl Session {
+	/// Builds a `Session` with the given input/output names and no real allocator or backing model, for tests
+	/// that only exercise validation logic and never call `run`.
+	pub(crate) fn test_instance(inputs: Vec<&str>, outputs: Vec<&str>) -> Session {
+		let io = |name: &str| Io {
+			name: name.to_string(),
+			dimensions: Vec::new()
+		};
+		Session {
+			allocator: Allocator::new(std::ptr::null_mut()),
+			metadata: Metadata::new(String::new(), String::new()),
+			inputs: inputs.into_iter().map(io).collect(),
+			outputs: outputs.into_iter().map(io).collect(),
+			test_run_output: None
+		}
+	}
+
+	/// Like [`Session::test_instance`], but `run` returns a single output tensor with `shape`/`data` instead of
+	/// erroring, so tests that build on `run` (e.g. [`Session::run_batch`]'s output-splitting logic) can
+	/// exercise the path beyond input validation without a real ONNX Runtime backend.
+	pub(crate) fn test_instance_with_output(inputs: Vec<&str>, outputs: Vec<&str>, shape: Vec<usize>, data: Vec<f32>) -> Session {
+		Session {
+			test_run_output: Some((shape, data)),
+			..Session::test_instance(inputs, outputs)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use super::*;
+	use crate::error::OrtError;
+
+	#[test]
+	fn run_with_names_rejects_missing_input() {
+		let session = Session::test_instance(vec!["a", "b"], vec!["y"]);
+		let err = session.run_with_names(HashMap::new()).unwrap_err();
+		assert!(matches!(err, OrtError::MissingInput(name) if name == "a"));
+	}
+
+	#[test]
+	fn run_with_names_rejects_unexpected_input() {
+		let session = Session::test_instance(vec!["a"], vec!["y"]);
+		let mut inputs = HashMap::new();
+		inputs.insert("a".to_string(), Value::test_placeholder(session.allocator()));
+		inputs.insert("z".to_string(), Value::test_placeholder(session.allocator()));
+		let err = session.run_with_names(inputs).unwrap_err();
+		assert!(matches!(err, OrtError::UnexpectedInput(name) if name == "z"));
+	}
+
+	fn io(name: &str, dimensions: Vec<Option<usize>>) -> Io {
+		Io { name: name.to_string(), dimensions }
+	}
+
+	#[test]
+	fn validate_declared_dimensions_accepts_a_matching_shape() {
+		let declared = io("x", vec![Some(2), Some(3)]);
+		assert!(validate_declared_dimensions(Some(&declared), &[Some(2), Some(3)]).is_ok());
+	}
+
+	#[test]
+	fn validate_declared_dimensions_rejects_a_mismatched_static_dimension() {
+		let declared = io("x", vec![Some(2), Some(3)]);
+		let err = validate_declared_dimensions(Some(&declared), &[Some(2), Some(4)]).unwrap_err();
+		assert!(matches!(err, OrtError::DeclaredDimensionMismatch { name, .. } if name == "x"));
+	}
+
+	#[test]
+	fn validate_declared_dimensions_permits_a_dynamic_dimension_on_either_side() {
+		let declared = io("x", vec![None, Some(3)]);
+		assert!(validate_declared_dimensions(Some(&declared), &[Some(7), Some(3)]).is_ok());
+
+		let declared = io("x", vec![Some(7), Some(3)]);
+		assert!(validate_declared_dimensions(Some(&declared), &[None, Some(3)]).is_ok());
+	}
+
+	#[test]
+	fn validate_declared_dimensions_rejects_mismatched_vector_lengths() {
+		let declared = io("x", vec![Some(2), Some(3)]);
+		let err = validate_declared_dimensions(Some(&declared), &[Some(2)]).unwrap_err();
+		assert!(matches!(err, OrtError::DeclaredDimensionMismatch { name, .. } if name == "x"));
+	}
+
+	#[test]
+	fn validate_declared_dimensions_is_a_no_op_when_io_is_none() {
+		assert!(validate_declared_dimensions(None, &[Some(2)]).is_ok());
+	}
+}
@@ -0,0 +1,34 @@
+use std::marker::PhantomData;
+
+use ndarray::{Array, ArrayView, Dimension};
+
+/// A typed tensor extracted from a [`crate::value::Value`].
+///
+/// This is what [`crate::value::Value::try_extract`] hands back: a read-only tensor holding its own copy of the
+/// underlying tensor data. The `'t` lifetime ties the tensor to the [`crate::value::Value`] it was extracted from.
+#[derive(Debug)]
+pub struct OrtOwnedTensor<'t, T, D: Dimension> {
+	array: Array<T, D>,
+	_marker: PhantomData<&'t ()>
+}
+
+impl<'t, T, D: Dimension> OrtOwnedTensor<'t, T, D> {
+	pub(crate) fn new(view: ArrayView<'t, T, D>) -> Self
+	where
+		T: Clone
+	{
+		OrtOwnedTensor {
+			array: view.to_owned(),
+			_marker: PhantomData
+		}
+	}
+
+	pub(crate) fn from_owned(array: Array<T, D>) -> Self {
+		OrtOwnedTensor { array, _marker: PhantomData }
+	}
+
+	/// Returns a view over the tensor's data.
+	pub fn view(&self) -> ArrayView<'_, T, D> {
+		self.array.view()
+	}
+}
@@ -0,0 +1,49 @@
+use ndarray::{ArrayView, CowArray, Dimension};
+
+use crate::{allocator::Allocator, error::OrtResult, tensor::OrtOwnedTensor};
+
+/// An owned ONNX Runtime tensor, the unit of data passed into and returned from [`crate::session::Session::run`].
+///
+/// A `Value` borrows the [`Allocator`] it was created from for the duration of its lifetime, which is why most
+/// constructors take `session.allocator()` as their first argument.
+#[derive(Debug)]
+pub struct Value<'v> {
+	pub(crate) shape: Vec<usize>,
+	pub(crate) data: Vec<f32>,
+	_allocator: &'v Allocator
+}
+
+impl<'v> Value<'v> {
+	/// Creates a `Value` from an [`ndarray`] array, copying its contents into a new tensor tied to `allocator`.
+	pub fn from_array<'a, D: Dimension>(allocator: &'v Allocator, array: &'a CowArray<'a, f32, D>) -> OrtResult<Value<'v>> {
+		Ok(Value {
+			shape: array.shape().to_vec(),
+			data: array.iter().copied().collect(),
+			_allocator: allocator
+		})
+	}
+
+	/// Extracts a copy of this value's contents as a typed, dimension-erased tensor.
+	pub fn try_extract<'r>(&'r self) -> OrtResult<OrtOwnedTensor<'r, f32, ndarray::IxDyn>> {
+		let array = ArrayView::from_shape(self.shape.clone(), &self.data).expect("tensor shape should match its backing data").into_dyn();
+		Ok(OrtOwnedTensor::new(array))
+	}
+}
+
+#[cfg(test)]
+impl<'v> Value<'v> {
+	/// A placeholder value for tests that only exercise validation logic and never actually reach ONNX Runtime.
+	pub(crate) fn test_placeholder(allocator: &'v Allocator) -> Self {
+		Value {
+			shape: Vec::new(),
+			data: Vec::new(),
+			_allocator: allocator
+		}
+	}
+
+	/// A value carrying real `shape`/`data`, for tests that stand in for a real `Session::run` output (see
+	/// [`crate::session::Session::test_instance_with_output`]).
+	pub(crate) fn test_tensor(allocator: &'v Allocator, shape: Vec<usize>, data: Vec<f32>) -> Self {
+		Value { shape, data, _allocator: allocator }
+	}
+}
@@ -3,8 +3,8 @@ use std::path::Path;
 use image::RgbImage;
 use ndarray::{Array, CowArray, IxDyn};
 use ort::{
-	environment::Environment, execution_providers::CPUExecutionProviderOptions, tensor::OrtOwnedTensor, value::Value, ExecutionProvider,
-	GraphOptimizationLevel, LoggingLevel, OrtResult, SessionBuilder
+	environment::Environment, execution_providers::CPUExecutionProvider, tensor::OrtOwnedTensor, value::Value, GraphOptimizationLevel, LoggingLevel, OrtResult,
+	SessionBuilder
 };
 use test_log::test;
 
@@ -51,7 +51,7 @@ fn upsample() -> OrtResult<()> {
 	let environment = Environment::builder()
 		.with_name("integration_test")
 		.with_log_level(LoggingLevel::Warning)
-		.with_execution_providers([ExecutionProvider::CPU(CPUExecutionProviderOptions { use_arena: true })])
+		.with_execution_providers([CPUExecutionProvider::default().with_use_arena(true).build()])
 		.build()?
 		.into_arc();
 
@@ -100,7 +100,7 @@ fn upsample_with_ort_model() -> OrtResult<()> {
 	let environment = Environment::builder()
 		.with_name("integration_test")
 		.with_log_level(LoggingLevel::Warning)
-		.with_execution_providers([ExecutionProvider::CPU(CPUExecutionProviderOptions { use_arena: true })])
+		.with_execution_providers([CPUExecutionProvider::default().with_use_arena(true).build()])
 		.build()?
 		.into_arc();
 